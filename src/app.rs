@@ -10,11 +10,14 @@ use crate::{
         command, ConnectionsComponent, DatabasesComponent, ErrorComponent, HelpComponent,
         PropertiesComponent, RecordTableComponent, SqlEditorComponent, TabComponent,
     },
-    config::Config,
+    config::{Config, Connection},
 };
+use std::io::Write as _;
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
 
@@ -23,6 +26,700 @@ pub enum Focus {
     Table,
     ConnectionList,
 }
+
+/// A small prompt for the destination path of a `Tab::Records` export,
+/// opened with `KeyConfig::export` and confirmed with enter.
+#[derive(Default)]
+struct ExportPromptComponent {
+    visible: bool,
+    input: String,
+}
+
+impl ExportPromptComponent {
+    fn open(&mut self) {
+        self.visible = true;
+        self.input.clear();
+    }
+
+    fn close(&mut self) {
+        self.visible = false;
+        self.input.clear();
+    }
+
+    fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if !self.visible {
+            return Ok(EventState::NotConsumed);
+        }
+        match key {
+            Key::Esc => self.close(),
+            Key::Char(c) => self.input.push(c),
+            Key::Backspace => {
+                self.input.pop();
+            }
+            _ => return Ok(EventState::NotConsumed),
+        }
+        Ok(EventState::Consumed)
+    }
+
+    fn draw<B: Backend>(&self, f: &mut Frame<'_, B>, area: Rect) -> anyhow::Result<()> {
+        if !self.visible {
+            return Ok(());
+        }
+        let rect = Rect {
+            x: area.width / 4,
+            y: area.height / 2,
+            width: area.width / 2,
+            height: 3,
+        };
+        let paragraph = Paragraph::new(self.input.as_str()).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Green))
+                .title("Export to (.csv / .json)"),
+        );
+        f.render_widget(paragraph, rect);
+        Ok(())
+    }
+}
+
+/// Which input of the connection editor currently has focus; `Tab` advances
+/// through the variants in declaration order and wraps back to `Type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum ConnectionFormField {
+    #[default]
+    Type,
+    Name,
+    Host,
+    Port,
+    User,
+    Password,
+    Database,
+}
+
+impl ConnectionFormField {
+    fn next(self) -> Self {
+        match self {
+            Self::Type => Self::Name,
+            Self::Name => Self::Host,
+            Self::Host => Self::Port,
+            Self::Port => Self::User,
+            Self::User => Self::Password,
+            Self::Password => Self::Database,
+            Self::Database => Self::Type,
+        }
+    }
+}
+
+enum ConnectionFormMode {
+    Create,
+    Edit(usize),
+}
+
+/// Create/edit form for `Focus::ConnectionList`. Collects the fields needed to
+/// build a `database_url` for any of the three backends, runs a "test
+/// connection" (open then immediately close a pool), and on submit writes the
+/// resulting `Connection` back into `config.conn` and persists it to disk.
+struct ConnectionFormComponent {
+    visible: bool,
+    mode: ConnectionFormMode,
+    field: ConnectionFormField,
+    r#type: String,
+    name: String,
+    host: String,
+    port: String,
+    user: String,
+    password: String,
+    database: String,
+    status: Option<String>,
+}
+
+impl Default for ConnectionFormComponent {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            mode: ConnectionFormMode::Create,
+            field: ConnectionFormField::default(),
+            r#type: "mysql".to_string(),
+            name: String::new(),
+            host: String::new(),
+            port: String::new(),
+            user: String::new(),
+            password: String::new(),
+            database: String::new(),
+            status: None,
+        }
+    }
+}
+
+impl ConnectionFormComponent {
+    fn open_create(&mut self) {
+        *self = Self {
+            visible: true,
+            mode: ConnectionFormMode::Create,
+            ..Self::default()
+        };
+    }
+
+    fn open_edit(&mut self, index: usize, conn: &Connection) {
+        *self = Self {
+            visible: true,
+            mode: ConnectionFormMode::Edit(index),
+            r#type: conn.r#type.clone().unwrap_or_else(|| "mysql".to_string()),
+            name: conn.name.clone().unwrap_or_default(),
+            host: conn.host.clone().unwrap_or_default(),
+            port: conn.port.map(|port| port.to_string()).unwrap_or_default(),
+            user: conn.user.clone().unwrap_or_default(),
+            password: conn.password.clone().unwrap_or_default(),
+            database: conn.database.clone().unwrap_or_default(),
+            ..Self::default()
+        };
+    }
+
+    fn close(&mut self) {
+        self.visible = false;
+    }
+
+    fn cycle_type(&mut self) {
+        self.r#type = match self.r#type.as_str() {
+            "mysql" => "postgres",
+            "postgres" => "sqlite",
+            _ => "mysql",
+        }
+        .to_string();
+    }
+
+    fn field_mut(&mut self) -> Option<&mut String> {
+        match self.field {
+            ConnectionFormField::Type => None,
+            ConnectionFormField::Name => Some(&mut self.name),
+            ConnectionFormField::Host => Some(&mut self.host),
+            ConnectionFormField::Port => Some(&mut self.port),
+            ConnectionFormField::User => Some(&mut self.user),
+            ConnectionFormField::Password => Some(&mut self.password),
+            ConnectionFormField::Database => Some(&mut self.database),
+        }
+    }
+
+    fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if !self.visible {
+            return Ok(EventState::NotConsumed);
+        }
+        match key {
+            Key::Esc => self.close(),
+            Key::Tab => self.field = self.field.next(),
+            Key::Left | Key::Right if self.field == ConnectionFormField::Type => {
+                self.cycle_type();
+            }
+            Key::Char(c) => {
+                if let Some(field) = self.field_mut() {
+                    field.push(c);
+                }
+            }
+            Key::Backspace => {
+                if let Some(field) = self.field_mut() {
+                    field.pop();
+                }
+            }
+            _ => return Ok(EventState::NotConsumed),
+        }
+        Ok(EventState::Consumed)
+    }
+
+    /// Builds the `database_url` the form currently describes, without requiring
+    /// a saved `Connection` — used by both the test-connection action and submit.
+    fn database_url(&self) -> String {
+        match self.r#type.as_str() {
+            "postgres" => format!(
+                "postgres://{}:{}@{}:{}/{}",
+                self.user, self.password, self.host, self.port, self.database
+            ),
+            "sqlite" => self.database.clone(),
+            _ => format!(
+                "mysql://{}:{}@{}:{}/{}",
+                self.user, self.password, self.host, self.port, self.database
+            ),
+        }
+    }
+
+    fn to_connection(&self) -> Connection {
+        Connection {
+            r#type: Some(self.r#type.clone()),
+            name: Some(self.name.clone()),
+            host: Some(self.host.clone()),
+            port: self.port.parse().ok(),
+            user: Some(self.user.clone()),
+            password: Some(self.password.clone()),
+            database: Some(self.database.clone()),
+        }
+    }
+
+    fn draw<B: Backend>(&self, f: &mut Frame<'_, B>, area: Rect) -> anyhow::Result<()> {
+        if !self.visible {
+            return Ok(());
+        }
+        let rect = Rect {
+            x: area.width / 4,
+            y: area.height / 4,
+            width: area.width / 2,
+            height: area.height / 2,
+        };
+        let title = match self.mode {
+            ConnectionFormMode::Create => "New connection",
+            ConnectionFormMode::Edit(_) => "Edit connection",
+        };
+        let body = format!(
+            "type:     {}\nname:     {}\nhost:     {}\nport:     {}\nuser:     {}\npassword: {}\ndatabase: {}\n{}",
+            self.r#type,
+            self.name,
+            self.host,
+            self.port,
+            self.user,
+            "*".repeat(self.password.len()),
+            self.database,
+            self.status.as_deref().unwrap_or(""),
+        );
+        let paragraph = Paragraph::new(body).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Green))
+                .title(title),
+        );
+        f.render_widget(paragraph, rect);
+        Ok(())
+    }
+}
+
+/// Overlay for editing the currently selected cell of `record_table` in place,
+/// opened with `KeyConfig::edit_cell` and confirmed with enter.
+#[derive(Default)]
+struct CellEditComponent {
+    visible: bool,
+    buffer: String,
+    row: usize,
+    column: usize,
+}
+
+impl CellEditComponent {
+    fn open(&mut self, row: usize, column: usize, initial: String) {
+        self.visible = true;
+        self.row = row;
+        self.column = column;
+        self.buffer = initial;
+    }
+
+    fn close(&mut self) {
+        self.visible = false;
+        self.buffer.clear();
+    }
+
+    fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if !self.visible {
+            return Ok(EventState::NotConsumed);
+        }
+        match key {
+            Key::Esc => self.close(),
+            Key::Char(c) => self.buffer.push(c),
+            Key::Backspace => {
+                self.buffer.pop();
+            }
+            _ => return Ok(EventState::NotConsumed),
+        }
+        Ok(EventState::Consumed)
+    }
+
+    fn draw<B: Backend>(&self, f: &mut Frame<'_, B>, area: Rect) -> anyhow::Result<()> {
+        if !self.visible {
+            return Ok(());
+        }
+        let rect = Rect {
+            x: area.width / 4,
+            y: area.height / 2,
+            width: area.width / 2,
+            height: 3,
+        };
+        let paragraph = Paragraph::new(self.buffer.as_str()).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Green))
+                .title("Edit cell"),
+        );
+        f.render_widget(paragraph, rect);
+        Ok(())
+    }
+}
+
+/// Prompt for a new row's values, one field per `record_table` column, opened
+/// with `KeyConfig::insert_row`. `Tab` moves between fields; the filled-in
+/// values are handed to `ConfirmPromptComponent` rather than executed directly,
+/// so the parameterized INSERT still goes through the same y/n confirmation as
+/// cell edits and row deletes.
+#[derive(Default)]
+struct InsertRowComponent {
+    visible: bool,
+    field: usize,
+    values: Vec<String>,
+}
+
+impl InsertRowComponent {
+    fn open(&mut self, headers: &[String]) {
+        self.visible = true;
+        self.field = 0;
+        self.values = vec![String::new(); headers.len()];
+    }
+
+    fn close(&mut self) {
+        self.visible = false;
+        self.values.clear();
+    }
+
+    fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if !self.visible {
+            return Ok(EventState::NotConsumed);
+        }
+        match key {
+            Key::Esc => self.close(),
+            Key::Tab if !self.values.is_empty() => {
+                self.field = (self.field + 1) % self.values.len();
+            }
+            Key::Char(c) => {
+                if let Some(value) = self.values.get_mut(self.field) {
+                    value.push(c);
+                }
+            }
+            Key::Backspace => {
+                if let Some(value) = self.values.get_mut(self.field) {
+                    value.pop();
+                }
+            }
+            _ => return Ok(EventState::NotConsumed),
+        }
+        Ok(EventState::Consumed)
+    }
+
+    fn draw<B: Backend>(
+        &self,
+        f: &mut Frame<'_, B>,
+        area: Rect,
+        headers: &[String],
+    ) -> anyhow::Result<()> {
+        if !self.visible {
+            return Ok(());
+        }
+        let rect = Rect {
+            x: area.width / 4,
+            y: area.height / 4,
+            width: area.width / 2,
+            height: area.height / 2,
+        };
+        let body = headers
+            .iter()
+            .zip(self.values.iter())
+            .enumerate()
+            .map(|(index, (header, value))| {
+                let marker = if index == self.field { ">" } else { " " };
+                format!("{marker} {header}: {value}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let paragraph = Paragraph::new(body).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Green))
+                .title("New row (tab to move, enter to confirm)"),
+        );
+        f.render_widget(paragraph, rect);
+        Ok(())
+    }
+}
+
+/// A mutation awaiting the user's y/n in `ConfirmPromptComponent`, carrying
+/// whatever the action needs to actually run against the `Pool` on confirm.
+enum PendingMutation {
+    UpdateCell {
+        row: usize,
+        column: usize,
+        value: String,
+    },
+    DeleteRow {
+        row: usize,
+    },
+    InsertRow {
+        values: Vec<String>,
+    },
+}
+
+/// A generic yes/no confirmation used before any `record_table` mutation is
+/// sent to the `Pool` (cell update, row delete, row insert).
+#[derive(Default)]
+struct ConfirmPromptComponent {
+    visible: bool,
+    message: String,
+    pending: Option<PendingMutation>,
+}
+
+impl ConfirmPromptComponent {
+    fn open(&mut self, message: String, pending: PendingMutation) {
+        self.visible = true;
+        self.message = message;
+        self.pending = Some(pending);
+    }
+
+    fn close(&mut self) {
+        self.visible = false;
+        self.pending = None;
+    }
+
+    fn draw<B: Backend>(&self, f: &mut Frame<'_, B>, area: Rect) -> anyhow::Result<()> {
+        if !self.visible {
+            return Ok(());
+        }
+        let rect = Rect {
+            x: area.width / 4,
+            y: area.height / 2,
+            width: area.width / 2,
+            height: 3,
+        };
+        let paragraph = Paragraph::new(format!("{} (y/n)", self.message)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Green))
+                .title("Confirm"),
+        );
+        f.render_widget(paragraph, rect);
+        Ok(())
+    }
+}
+
+/// One successfully executed `Tab::Sql` statement, scoped to the connection it
+/// ran against so switching connections in `update_databases` shows only the
+/// relevant history.
+#[derive(Clone)]
+struct QueryHistoryEntry {
+    connection: String,
+    query: String,
+    executed_at: u64,
+}
+
+/// Searchable picker over `QueryHistoryEntry`s for the current connection,
+/// opened with `KeyConfig::history` while `Tab::Sql` is focused.
+#[derive(Default)]
+struct HistoryComponent {
+    visible: bool,
+    filter: String,
+    entries: Vec<QueryHistoryEntry>,
+    selected: usize,
+}
+
+impl HistoryComponent {
+    fn open(&mut self) {
+        self.visible = true;
+        self.filter.clear();
+        self.selected = 0;
+    }
+
+    fn close(&mut self) {
+        self.visible = false;
+    }
+
+    fn filtered(&self) -> Vec<&QueryHistoryEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .filter(|entry| entry.query.contains(self.filter.as_str()))
+            .collect()
+    }
+
+    /// `match_count` is the number of entries `filtered()` currently returns;
+    /// the caller computes it before the event so `Down` can clamp to it
+    /// instead of walking `selected` past the end of the visible list.
+    fn event(&mut self, key: Key, match_count: usize) -> anyhow::Result<EventState> {
+        if !self.visible {
+            return Ok(EventState::NotConsumed);
+        }
+        match key {
+            Key::Esc => self.close(),
+            Key::Down => {
+                if match_count > 0 {
+                    self.selected = (self.selected + 1).min(match_count - 1);
+                }
+            }
+            Key::Up => self.selected = self.selected.saturating_sub(1),
+            Key::Char(c) => {
+                self.filter.push(c);
+                self.selected = 0;
+            }
+            Key::Backspace => {
+                self.filter.pop();
+                self.selected = 0;
+            }
+            _ => return Ok(EventState::NotConsumed),
+        }
+        Ok(EventState::Consumed)
+    }
+
+    fn selected_query(&self) -> Option<String> {
+        self.filtered()
+            .get(self.selected)
+            .map(|entry| entry.query.clone())
+    }
+
+    fn draw<B: Backend>(&self, f: &mut Frame<'_, B>, area: Rect) -> anyhow::Result<()> {
+        if !self.visible {
+            return Ok(());
+        }
+        let rect = Rect {
+            x: area.width / 6,
+            y: area.height / 6,
+            width: (area.width * 2) / 3,
+            height: (area.height * 2) / 3,
+        };
+        let items: Vec<ListItem> = self
+            .filtered()
+            .into_iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let style = if index == self.selected {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(format!("[{}] {}", entry.executed_at, entry.query)).style(style)
+            })
+            .collect();
+        let list = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Query history ({})", self.filter)),
+        );
+        f.render_widget(list, rect);
+        Ok(())
+    }
+}
+
+/// Scores `haystack` against `needle` as a fuzzy subsequence match: every
+/// character of `needle` (case-insensitively) must appear in `haystack` in
+/// order, and tighter, shorter matches score higher. Returns `None` when
+/// `needle` is not a subsequence of `haystack` at all.
+fn fuzzy_score(haystack: &str, needle: &str) -> Option<i32> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+
+    let haystack_lower: Vec<char> = haystack.to_lowercase().chars().collect();
+    let needle_lower: Vec<char> = needle.to_lowercase().chars().collect();
+
+    let mut score = 0i32;
+    let mut cursor = 0usize;
+    for needle_char in &needle_lower {
+        let gap = haystack_lower[cursor..]
+            .iter()
+            .position(|haystack_char| haystack_char == needle_char)?;
+        score -= gap as i32;
+        cursor += gap + 1;
+    }
+    score += 100 - haystack_lower.len().min(100) as i32;
+    Some(score)
+}
+
+/// Fuzzy-filter overlay over every database/table name in the current
+/// connection's tree, opened with `KeyConfig::fuzzy_find_databases` while
+/// `Focus::DabataseList` is focused. Narrows to matches via `fuzzy_score` and
+/// ranks them best-match-first.
+#[derive(Default)]
+struct DatabaseFilterComponent {
+    visible: bool,
+    filter: String,
+    selected: usize,
+}
+
+impl DatabaseFilterComponent {
+    fn open(&mut self) {
+        self.visible = true;
+        self.filter.clear();
+        self.selected = 0;
+    }
+
+    fn close(&mut self) {
+        self.visible = false;
+    }
+
+    fn matches(&self, nodes: &[(String, String)]) -> Vec<(String, String)> {
+        let mut scored: Vec<(i32, &(String, String))> = nodes
+            .iter()
+            .filter_map(|node| {
+                let label = format!("{}.{}", node.0, node.1);
+                fuzzy_score(&label, &self.filter).map(|score| (score, node))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+        scored.into_iter().map(|(_, node)| node.clone()).collect()
+    }
+
+    /// `match_count` is the number of entries `matches()` currently returns;
+    /// the caller computes it before the event so `Down` can clamp to it.
+    fn event(&mut self, key: Key, match_count: usize) -> anyhow::Result<EventState> {
+        if !self.visible {
+            return Ok(EventState::NotConsumed);
+        }
+        match key {
+            Key::Esc => self.close(),
+            Key::Down => {
+                if match_count > 0 {
+                    self.selected = (self.selected + 1).min(match_count - 1);
+                }
+            }
+            Key::Up => self.selected = self.selected.saturating_sub(1),
+            Key::Char(c) => {
+                self.filter.push(c);
+                self.selected = 0;
+            }
+            Key::Backspace => {
+                self.filter.pop();
+                self.selected = 0;
+            }
+            _ => return Ok(EventState::NotConsumed),
+        }
+        Ok(EventState::Consumed)
+    }
+
+    fn draw<B: Backend>(
+        &self,
+        f: &mut Frame<'_, B>,
+        area: Rect,
+        nodes: &[(String, String)],
+    ) -> anyhow::Result<()> {
+        if !self.visible {
+            return Ok(());
+        }
+        let rect = Rect {
+            x: area.width / 6,
+            y: area.height / 6,
+            width: (area.width * 2) / 3,
+            height: (area.height * 2) / 3,
+        };
+        let items: Vec<ListItem> = self
+            .matches(nodes)
+            .iter()
+            .enumerate()
+            .map(|(index, (database, table))| {
+                let style = if index == self.selected {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(format!("{database}.{table}")).style(style)
+            })
+            .collect();
+        let list = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Fuzzy find ({})", self.filter)),
+        );
+        f.render_widget(list, rect);
+        Ok(())
+    }
+}
+
 pub struct App {
     record_table: RecordTableComponent,
     properties: PropertiesComponent,
@@ -34,6 +731,13 @@ pub struct App {
     connections: ConnectionsComponent,
     pool: Option<Box<dyn Pool>>,
     left_main_chunk_percentage: u16,
+    export_prompt: ExportPromptComponent,
+    connection_form: ConnectionFormComponent,
+    cell_edit: CellEditComponent,
+    insert_row: InsertRowComponent,
+    confirm_prompt: ConfirmPromptComponent,
+    query_history: HistoryComponent,
+    database_filter: DatabaseFilterComponent,
     pub config: Config,
     pub error: ErrorComponent,
 }
@@ -53,6 +757,13 @@ impl App {
             focus: Focus::ConnectionList,
             pool: None,
             left_main_chunk_percentage: 15,
+            export_prompt: ExportPromptComponent::default(),
+            connection_form: ConnectionFormComponent::default(),
+            cell_edit: CellEditComponent::default(),
+            insert_row: InsertRowComponent::default(),
+            confirm_prompt: ConfirmPromptComponent::default(),
+            query_history: HistoryComponent::default(),
+            database_filter: DatabaseFilterComponent::default(),
         }
     }
 
@@ -65,6 +776,7 @@ impl App {
                     .split(f.size())[0],
                 false,
             )?;
+            self.connection_form.draw(f, f.size())?;
             self.error.draw(f, Rect::default(), false)?;
             self.help.draw(f, Rect::default(), false)?;
             return Ok(());
@@ -80,6 +792,8 @@ impl App {
 
         self.databases
             .draw(f, main_chunks[0], matches!(self.focus, Focus::DabataseList))?;
+        self.database_filter
+            .draw(f, main_chunks[0], &self.all_tables())?;
 
         let right_chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -96,12 +810,17 @@ impl App {
             Tab::Sql => {
                 self.sql_editor
                     .draw(f, right_chunks[1], matches!(self.focus, Focus::Table))?;
+                self.query_history.draw(f, f.size())?;
             }
             Tab::Properties => {
                 self.properties
                     .draw(f, right_chunks[1], matches!(self.focus, Focus::Table))?;
             }
         }
+        self.export_prompt.draw(f, f.size())?;
+        self.cell_edit.draw(f, f.size())?;
+        self.insert_row.draw(f, f.size(), &self.record_table.headers)?;
+        self.confirm_prompt.draw(f, f.size())?;
         self.error.draw(f, Rect::default(), false)?;
         self.help.draw(f, Rect::default(), false)?;
         Ok(())
@@ -125,6 +844,7 @@ impl App {
             CommandInfo::new(command::extend_or_shorten_widget_width(
                 &self.config.key_config,
             )),
+            CommandInfo::new(command::fuzzy_find_databases(&self.config.key_config)),
         ];
 
         self.databases.commands(&mut res);
@@ -136,6 +856,7 @@ impl App {
 
     async fn update_databases(&mut self) -> anyhow::Result<()> {
         if let Some(conn) = self.connections.selected_connection() {
+            let connection_name = conn.name.clone().unwrap_or_default();
             if let Some(pool) = self.pool.as_ref() {
                 pool.close().await;
             }
@@ -158,10 +879,42 @@ impl App {
             self.focus = Focus::DabataseList;
             self.record_table.reset();
             self.tab.reset();
+            self.query_history.entries =
+                load_query_history(&self.config.history_path(), &connection_name);
         }
         Ok(())
     }
 
+    /// Every (database, table) pair in the current connection's tree, for
+    /// `database_filter` to score and narrow.
+    fn all_tables(&self) -> Vec<(String, String)> {
+        self.databases.tree().all_tables()
+    }
+
+    /// Loads `database`/`table` into `record_table` and `properties` and moves
+    /// focus to `Focus::Table`, regardless of whether it was reached by
+    /// navigating the tree or by jumping straight there via `database_filter`.
+    async fn open_table(&mut self, database: &str, table: &str) -> anyhow::Result<()> {
+        self.record_table.reset();
+        let (headers, records) = self
+            .pool
+            .as_ref()
+            .unwrap()
+            .get_records(database, table, 0, None)
+            .await?;
+        self.record_table
+            .update(records, headers, database.to_string(), table.to_string());
+        self.properties
+            .update(
+                database.to_string(),
+                table.to_string(),
+                self.pool.as_ref().unwrap(),
+            )
+            .await?;
+        self.focus = Focus::Table;
+        Ok(())
+    }
+
     async fn update_record_table(&mut self) -> anyhow::Result<()> {
         if let Some((database, table)) = self.databases.tree().selected_table() {
             let (headers, records) = self
@@ -185,6 +938,194 @@ impl App {
         Ok(())
     }
 
+    /// Exports the full, unpaginated contents of the currently selected table to
+    /// the path typed into `export_prompt`, as CSV unless the path ends in `.json`.
+    async fn export_records(&mut self) -> anyhow::Result<()> {
+        let path = self.export_prompt.input.trim().to_string();
+        if path.is_empty() {
+            return Ok(());
+        }
+        let Some((database, table)) = self.databases.tree().selected_table() else {
+            return Ok(());
+        };
+
+        let (headers, rows) = self.fetch_all_records(&database, &table).await?;
+
+        if path.ends_with(".json") {
+            write_records_as_json(&path, &headers, &rows)?;
+        } else {
+            write_records_as_csv(&path, &headers, &rows)?;
+        }
+
+        Ok(())
+    }
+
+    /// Streams every page of `Pool::get_records` for `database`/`table`, passing
+    /// the number of rows loaded so far as the offset of the next request.
+    /// Stops at the first empty page, or once the row count can no longer be expressed as the page offset
+    /// `get_records` takes, instead of wrapping back to page 0 and looping forever.
+    async fn fetch_all_records(
+        &self,
+        database: &str,
+        table: &str,
+    ) -> anyhow::Result<(Vec<String>, Vec<Vec<String>>)> {
+        let pool = self.pool.as_ref().unwrap();
+        let (headers, mut rows) = pool.get_records(database, table, 0, None).await?;
+
+        while let Some(offset) = next_page_offset(rows.len()) {
+            let (_, next) = pool.get_records(database, table, offset, None).await?;
+            if next.is_empty() {
+                break;
+            }
+            rows.extend(next);
+        }
+
+        Ok((headers, rows))
+    }
+
+    /// Opens and immediately closes a pool for whatever the form currently
+    /// describes, reporting the outcome in `connection_form.status`.
+    async fn test_connection_form(&mut self) {
+        let url = self.connection_form.database_url();
+        let result = open_and_close_pool(&self.connection_form.r#type, &url).await;
+        self.connection_form.status = Some(match result {
+            Ok(()) => "connection ok".to_string(),
+            Err(err) => format!("failed: {err}"),
+        });
+    }
+
+    /// Validates the form by opening (and closing) a pool, then writes the
+    /// resulting `Connection` into `config.conn`, persists the config to disk,
+    /// and refreshes the on-screen connection list.
+    async fn submit_connection_form(&mut self) -> anyhow::Result<()> {
+        let url = self.connection_form.database_url();
+        if let Err(err) = open_and_close_pool(&self.connection_form.r#type, &url).await {
+            self.connection_form.status = Some(format!("failed: {err}"));
+            return Ok(());
+        }
+
+        let connection = self.connection_form.to_connection();
+        match self.connection_form.mode {
+            ConnectionFormMode::Create => self.config.conn.push(connection),
+            ConnectionFormMode::Edit(index) => self.config.conn[index] = connection,
+        }
+        self.config.save()?;
+        self.connections.update(self.config.conn.clone());
+        self.connection_form.close();
+        Ok(())
+    }
+
+    /// Runs whatever `confirm_prompt` was holding against the `Pool`, using
+    /// `PropertiesComponent`'s primary-key metadata to target the right row,
+    /// then refreshes `record_table` from the database.
+    async fn apply_pending_mutation(&mut self) -> anyhow::Result<()> {
+        let Some(pending) = self.confirm_prompt.pending.take() else {
+            self.confirm_prompt.close();
+            return Ok(());
+        };
+        self.confirm_prompt.close();
+
+        let Some((database, table)) = self.databases.tree().selected_table() else {
+            return Ok(());
+        };
+
+        match pending {
+            PendingMutation::UpdateCell { row, column, value } => {
+                let Some((primary_key, primary_key_index)) = self.primary_key_column_index()
+                else {
+                    return Ok(());
+                };
+                let (Some(primary_key_value), Some(column_name)) = (
+                    self.record_table
+                        .table
+                        .rows
+                        .get(row)
+                        .and_then(|cols| cols.get(primary_key_index)),
+                    self.record_table.headers.get(column),
+                ) else {
+                    return Ok(());
+                };
+                self.pool
+                    .as_ref()
+                    .unwrap()
+                    .update_record(
+                        &database,
+                        &table,
+                        &primary_key,
+                        primary_key_value,
+                        column_name,
+                        &value,
+                    )
+                    .await?;
+            }
+            PendingMutation::DeleteRow { row } => {
+                let Some((primary_key, primary_key_index)) = self.primary_key_column_index()
+                else {
+                    return Ok(());
+                };
+                let Some(primary_key_value) = self
+                    .record_table
+                    .table
+                    .rows
+                    .get(row)
+                    .and_then(|cols| cols.get(primary_key_index))
+                else {
+                    return Ok(());
+                };
+                self.pool
+                    .as_ref()
+                    .unwrap()
+                    .delete_record(&database, &table, &primary_key, primary_key_value)
+                    .await?;
+            }
+            PendingMutation::InsertRow { values } => {
+                self.pool
+                    .as_ref()
+                    .unwrap()
+                    .insert_record(&database, &table, &self.record_table.headers, &values)
+                    .await?;
+            }
+        }
+
+        self.update_record_table().await
+    }
+
+    /// The primary-key column name and its index among `record_table.headers`,
+    /// from `PropertiesComponent`'s metadata for the currently open table.
+    fn primary_key_column_index(&self) -> Option<(String, usize)> {
+        let primary_key = self.properties.primary_key_column()?;
+        let index = self
+            .record_table
+            .headers
+            .iter()
+            .position(|header| header == &primary_key)?;
+        Some((primary_key, index))
+    }
+
+    /// Appends the statement just run in `sql_editor` to the on-disk history
+    /// file and to `query_history`, scoped to the current connection's name.
+    async fn record_query_history(&mut self) -> anyhow::Result<()> {
+        let Some(conn) = self.connections.selected_connection() else {
+            return Ok(());
+        };
+        let query = self.sql_editor.input_str().to_string();
+        if query.trim().is_empty() {
+            return Ok(());
+        }
+
+        let entry = QueryHistoryEntry {
+            connection: conn.name.clone().unwrap_or_default(),
+            query,
+            executed_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        };
+        append_query_history(&self.config.history_path(), &entry)?;
+        self.query_history.entries.push(entry);
+        Ok(())
+    }
+
     pub async fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
         self.update_commands();
 
@@ -209,35 +1150,81 @@ impl App {
 
         match self.focus {
             Focus::ConnectionList => {
+                if self.connection_form.visible {
+                    if key == self.config.key_config.enter {
+                        self.submit_connection_form().await?;
+                    } else if key == self.config.key_config.test_connection {
+                        self.test_connection_form().await;
+                    } else {
+                        self.connection_form.event(key)?;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+
                 if self.connections.event(key)?.is_consumed() {
                     return Ok(EventState::Consumed);
                 }
 
+                if key == self.config.key_config.new_connection {
+                    self.connection_form.open_create();
+                    return Ok(EventState::Consumed);
+                }
+
+                if key == self.config.key_config.edit_connection {
+                    if let Some((index, conn)) = self.connections.selected_connection_with_index()
+                    {
+                        self.connection_form.open_edit(index, conn);
+                    }
+                    return Ok(EventState::Consumed);
+                }
+
+                if key == self.config.key_config.delete_connection {
+                    if let Some(index) = self.connections.selected_index() {
+                        self.config.conn.remove(index);
+                        self.config.save()?;
+                        self.connections.update(self.config.conn.clone());
+                    }
+                    return Ok(EventState::Consumed);
+                }
+
                 if key == self.config.key_config.enter {
                     self.update_databases().await?;
                     return Ok(EventState::Consumed);
                 }
             }
             Focus::DabataseList => {
+                if self.database_filter.visible {
+                    if key == self.config.key_config.enter {
+                        let nodes = self.all_tables();
+                        let selection = self
+                            .database_filter
+                            .matches(&nodes)
+                            .get(self.database_filter.selected)
+                            .map(|(database, table)| (database.clone(), table.clone()));
+                        self.database_filter.close();
+                        if let Some((database, table)) = selection {
+                            self.open_table(&database, &table).await?;
+                        }
+                    } else {
+                        let nodes = self.all_tables();
+                        let match_count = self.database_filter.matches(&nodes).len();
+                        self.database_filter.event(key, match_count)?;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+
+                if key == self.config.key_config.fuzzy_find_databases {
+                    self.database_filter.open();
+                    return Ok(EventState::Consumed);
+                }
+
                 if self.databases.event(key)?.is_consumed() {
                     return Ok(EventState::Consumed);
                 }
 
                 if key == self.config.key_config.enter && self.databases.tree_focused() {
                     if let Some((database, table)) = self.databases.tree().selected_table() {
-                        self.record_table.reset();
-                        let (headers, records) = self
-                            .pool
-                            .as_ref()
-                            .unwrap()
-                            .get_records(&database, &table, 0, None)
-                            .await?;
-                        self.record_table
-                            .update(records, headers, database.clone(), table.clone());
-                        self.properties
-                            .update(database.clone(), table.clone(), self.pool.as_ref().unwrap())
-                            .await?;
-                        self.focus = Focus::Table;
+                        self.open_table(&database, &table).await?;
                     }
                     return Ok(EventState::Consumed);
                 }
@@ -245,6 +1232,93 @@ impl App {
             Focus::Table => {
                 match self.tab.selected_tab {
                     Tab::Records => {
+                        if self.confirm_prompt.visible {
+                            if key == Key::Char('y') {
+                                self.apply_pending_mutation().await?;
+                            } else {
+                                self.confirm_prompt.close();
+                            }
+                            return Ok(EventState::Consumed);
+                        }
+
+                        if self.cell_edit.visible {
+                            if key == self.config.key_config.enter {
+                                let CellEditComponent {
+                                    row,
+                                    column,
+                                    buffer,
+                                    ..
+                                } = std::mem::take(&mut self.cell_edit);
+                                self.confirm_prompt.open(
+                                    format!("update row {row} column {column} to {buffer:?}"),
+                                    PendingMutation::UpdateCell {
+                                        row,
+                                        column,
+                                        value: buffer,
+                                    },
+                                );
+                            } else {
+                                self.cell_edit.event(key)?;
+                            }
+                            return Ok(EventState::Consumed);
+                        }
+
+                        if self.insert_row.visible {
+                            if key == self.config.key_config.enter {
+                                let InsertRowComponent { values, .. } =
+                                    std::mem::take(&mut self.insert_row);
+                                self.confirm_prompt.open(
+                                    format!("insert row {values:?}"),
+                                    PendingMutation::InsertRow { values },
+                                );
+                            } else {
+                                self.insert_row.event(key)?;
+                            }
+                            return Ok(EventState::Consumed);
+                        }
+
+                        if self.export_prompt.visible {
+                            if key == self.config.key_config.enter {
+                                self.export_records().await?;
+                                self.export_prompt.close();
+                            } else {
+                                self.export_prompt.event(key)?;
+                            }
+                            return Ok(EventState::Consumed);
+                        }
+
+                        if key == self.config.key_config.export {
+                            self.export_prompt.open();
+                            return Ok(EventState::Consumed);
+                        }
+
+                        if key == self.config.key_config.edit_cell {
+                            if let (Some(row), Some(text)) = (
+                                self.record_table.table.selected_row.selected(),
+                                self.record_table.table.selected_cells(),
+                            ) {
+                                let column =
+                                    self.record_table.table.selected_column.selected().unwrap_or(0);
+                                self.cell_edit.open(row, column, text);
+                            }
+                            return Ok(EventState::Consumed);
+                        }
+
+                        if key == self.config.key_config.delete_row {
+                            if let Some(row) = self.record_table.table.selected_row.selected() {
+                                self.confirm_prompt.open(
+                                    format!("delete row {row}"),
+                                    PendingMutation::DeleteRow { row },
+                                );
+                            }
+                            return Ok(EventState::Consumed);
+                        }
+
+                        if key == self.config.key_config.insert_row {
+                            self.insert_row.open(&self.record_table.headers);
+                            return Ok(EventState::Consumed);
+                        }
+
                         if self.record_table.event(key)?.is_consumed() {
                             return Ok(EventState::Consumed);
                         };
@@ -295,13 +1369,37 @@ impl App {
                         };
                     }
                     Tab::Sql => {
-                        if self.sql_editor.event(key)?.is_consumed()
-                            || self
-                                .sql_editor
-                                .async_event(key, self.pool.as_ref().unwrap())
-                                .await?
-                                .is_consumed()
+                        if self.query_history.visible {
+                            if key == self.config.key_config.enter {
+                                if let Some(query) = self.query_history.selected_query() {
+                                    self.sql_editor.set_input(query);
+                                }
+                                self.query_history.close();
+                            } else {
+                                let match_count = self.query_history.filtered().len();
+                                self.query_history.event(key, match_count)?;
+                            }
+                            return Ok(EventState::Consumed);
+                        }
+
+                        if key == self.config.key_config.history {
+                            self.query_history.open();
+                            return Ok(EventState::Consumed);
+                        }
+
+                        if self.sql_editor.event(key)?.is_consumed() {
+                            return Ok(EventState::Consumed);
+                        };
+
+                        if self
+                            .sql_editor
+                            .async_event(key, self.pool.as_ref().unwrap())
+                            .await?
+                            .is_consumed()
                         {
+                            if key == self.config.key_config.enter {
+                                self.record_query_history().await?;
+                            }
                             return Ok(EventState::Consumed);
                         };
                     }
@@ -375,6 +1473,115 @@ impl App {
     }
 }
 
+/// Opens a pool for `r#type`/`url` and closes it right away; used to validate a
+/// connection before it is saved to `config.conn`.
+async fn open_and_close_pool(r#type: &str, url: &str) -> anyhow::Result<()> {
+    let pool: Box<dyn Pool> = match r#type {
+        "postgres" => Box::new(PostgresPool::new(url).await?),
+        "sqlite" => Box::new(SqlitePool::new(url).await?),
+        _ => Box::new(MySqlPool::new(url).await?),
+    };
+    pool.close().await;
+    Ok(())
+}
+
+fn history_entry_line(entry: &QueryHistoryEntry) -> String {
+    format!(
+        "{}\t{}\t{}",
+        entry.executed_at,
+        entry.connection,
+        entry.query.replace('\n', " ")
+    )
+}
+
+fn append_query_history(path: &std::path::Path, entry: &QueryHistoryEntry) -> anyhow::Result<()> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    writeln!(file, "{}", history_entry_line(entry))?;
+    Ok(())
+}
+
+fn load_query_history(path: &std::path::Path, connection: &str) -> Vec<QueryHistoryEntry> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '\t');
+            let executed_at = parts.next()?.parse().ok()?;
+            let entry_connection = parts.next()?.to_string();
+            let query = parts.next()?.to_string();
+            (entry_connection == connection).then_some(QueryHistoryEntry {
+                connection: entry_connection,
+                query,
+                executed_at,
+            })
+        })
+        .collect()
+}
+
+/// The page offset to request next, or `None` once `rows_loaded` can no
+/// longer fit in the `u16` page offset `Pool::get_records` takes.
+fn next_page_offset(rows_loaded: usize) -> Option<u16> {
+    u16::try_from(rows_loaded).ok()
+}
+
+/// Quotes `value` per RFC 4180 if it contains a comma, quote, or newline.
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn csv_row(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|value| csv_escape(value))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn records_to_csv(headers: &[String], rows: &[Vec<String>]) -> String {
+    let mut csv = String::new();
+    csv.push_str(&csv_row(headers));
+    csv.push('\n');
+    for row in rows {
+        csv.push_str(&csv_row(row));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn write_records_as_csv(path: &str, headers: &[String], rows: &[Vec<String>]) -> anyhow::Result<()> {
+    std::fs::write(path, records_to_csv(headers, rows))?;
+    Ok(())
+}
+
+fn records_to_json(headers: &[String], rows: &[Vec<String>]) -> anyhow::Result<String> {
+    let objects: Vec<serde_json::Value> = rows
+        .iter()
+        .map(|row| {
+            let object: serde_json::Map<String, serde_json::Value> = headers
+                .iter()
+                .zip(row.iter())
+                .map(|(header, value)| (header.clone(), serde_json::Value::String(value.clone())))
+                .collect();
+            serde_json::Value::Object(object)
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&objects)?)
+}
+
+fn write_records_as_json(path: &str, headers: &[String], rows: &[Vec<String>]) -> anyhow::Result<()> {
+    std::fs::write(path, records_to_json(headers, rows)?)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use super::{App, Config, EventState, Key};
@@ -408,4 +1615,106 @@ mod test {
         );
         assert_eq!(app.left_main_chunk_percentage, 15);
     }
+
+    #[test]
+    fn test_csv_escaping_for_commas_quotes_and_newlines() {
+        assert_eq!(super::csv_escape("plain"), "plain");
+        assert_eq!(super::csv_escape("a,b"), "\"a,b\"");
+        assert_eq!(super::csv_escape("a\"b"), "\"a\"\"b\"");
+        assert_eq!(super::csv_escape("a\nb"), "\"a\nb\"");
+    }
+
+    #[test]
+    fn test_csv_output_for_tricky_values() {
+        let headers = vec!["name".to_string(), "bio".to_string()];
+        let rows = vec![vec![
+            "a,b".to_string(),
+            "line1\nline2 \"quoted\"".to_string(),
+        ]];
+        let csv = super::records_to_csv(&headers, &rows);
+        assert_eq!(csv, "name,bio\n\"a,b\",\"line1\nline2 \"\"quoted\"\"\"\n");
+    }
+
+    #[test]
+    fn test_json_round_trip_for_tricky_values() {
+        let headers = vec!["name".to_string(), "bio".to_string()];
+        let rows = vec![vec![
+            "a,b".to_string(),
+            "line1\nline2 \"quoted\"".to_string(),
+        ]];
+        let json = super::records_to_json(&headers, &rows).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed[0]["name"], "a,b");
+        assert_eq!(parsed[0]["bio"], "line1\nline2 \"quoted\"");
+    }
+
+    #[test]
+    fn test_next_page_offset_stops_before_wrapping() {
+        assert_eq!(super::next_page_offset(0), Some(0));
+        assert_eq!(super::next_page_offset(65_535), Some(65_535));
+        assert_eq!(super::next_page_offset(65_536), None);
+    }
+
+    #[test]
+    fn test_query_history_round_trips_and_scopes_by_connection() {
+        let path = std::env::temp_dir().join(format!(
+            "gobang_test_history_{}_{}.tsv",
+            std::process::id(),
+            "round_trip"
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let entry_a = super::QueryHistoryEntry {
+            connection: "a".to_string(),
+            query: "select 1".to_string(),
+            executed_at: 1,
+        };
+        let entry_b = super::QueryHistoryEntry {
+            connection: "b".to_string(),
+            query: "select 2".to_string(),
+            executed_at: 2,
+        };
+        super::append_query_history(&path, &entry_a).unwrap();
+        super::append_query_history(&path, &entry_b).unwrap();
+
+        let loaded_a = super::load_query_history(&path, "a");
+        assert_eq!(loaded_a.len(), 1);
+        assert_eq!(loaded_a[0].query, "select 1");
+        assert_eq!(loaded_a[0].executed_at, 1);
+
+        let loaded_b = super::load_query_history(&path, "b");
+        assert_eq!(loaded_b.len(), 1);
+        assert_eq!(loaded_b[0].query, "select 2");
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_connection_form_field_next_wraps_back_to_type() {
+        let mut field = super::ConnectionFormField::Type;
+        let sequence = [
+            super::ConnectionFormField::Name,
+            super::ConnectionFormField::Host,
+            super::ConnectionFormField::Port,
+            super::ConnectionFormField::User,
+            super::ConnectionFormField::Password,
+            super::ConnectionFormField::Database,
+            super::ConnectionFormField::Type,
+        ];
+        for expected in sequence {
+            field = field.next();
+            assert_eq!(field, expected);
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_score_matches_subsequence_and_ranks_tighter_matches_higher() {
+        assert_eq!(super::fuzzy_score("users", ""), Some(0));
+        assert!(super::fuzzy_score("users", "usr").is_some());
+        assert!(super::fuzzy_score("users", "xyz").is_none());
+
+        let tight = super::fuzzy_score("public.users", "users").unwrap();
+        let loose = super::fuzzy_score("public.user_sessions", "users").unwrap();
+        assert!(tight > loose);
+    }
 }